@@ -0,0 +1,188 @@
+// Copyright 2025 The Axvisor Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Hypercall ABI versioning.
+//!
+//! AxVisor's hypercall ABI evolves over time: argument layouts can change and
+//! new codes can be added without breaking guests that were built against an
+//! older revision. A guest and the hypervisor agree on a common revision
+//! through [`HyperCallCode::HyperVisorNegotiateAbi`] before relying on
+//! anything introduced after [`HyperCallAbi::V1`].
+
+use crate::HyperCallCode;
+
+/// A revision of the hypercall ABI.
+///
+/// Variants are ordered from oldest to newest, so `abi as u32` can be used to
+/// compare revisions directly.
+#[repr(u32)]
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
+pub enum HyperCallAbi {
+    /// The original ABI: hypervisor control and IVC shared-memory channels.
+    V1 = 1,
+
+    /// Adds capability enumeration, the IVC notify/wait doorbell hypercalls,
+    /// and the control/self-service privilege split.
+    V2 = 2,
+}
+
+impl HyperCallAbi {
+    /// All known ABI revisions, oldest first.
+    pub const ALL: &'static [HyperCallAbi] = &[HyperCallAbi::V1, HyperCallAbi::V2];
+
+    /// The newest ABI revision this build of the crate understands.
+    pub const LATEST: HyperCallAbi = HyperCallAbi::V2;
+
+    /// Decodes an ABI revision from its wire value.
+    pub fn from_u32(value: u32) -> Option<HyperCallAbi> {
+        match value {
+            1 => Some(HyperCallAbi::V1),
+            2 => Some(HyperCallAbi::V2),
+            _ => None,
+        }
+    }
+
+    /// Given a bitmask of ABI revisions the guest is willing to speak (bit
+    /// `n` set means "I support `HyperCallAbi::from_u32(n)`"), returns the
+    /// highest revision both sides support, if any.
+    pub fn negotiate(guest_mask: u64) -> Option<HyperCallAbi> {
+        HyperCallAbi::ALL
+            .iter()
+            .copied()
+            .rev()
+            .find(|abi| guest_mask & (1 << (*abi as u32)) != 0)
+    }
+}
+
+impl HyperCallCode {
+    /// The oldest ABI revision under which this code is valid.
+    ///
+    /// The dispatcher uses this to reject a code that postdates the ABI
+    /// revision negotiated for the calling guest, e.g. a `V2` code invoked
+    /// by a guest that only negotiated `V1`. Derived from the [`v1::CODES`]
+    /// / [`v2::CODES`] sets, which are the actual source of truth for which
+    /// revision introduced each code.
+    pub fn min_abi(&self) -> HyperCallAbi {
+        if v1::CODES.contains(self) {
+            HyperCallAbi::V1
+        } else {
+            debug_assert!(
+                v2::CODES.contains(self),
+                "code missing from every ABI revision's code set"
+            );
+            HyperCallAbi::V2
+        }
+    }
+
+    /// Decodes a hypercall code, rejecting codes newer than `abi`.
+    ///
+    /// This is the ABI-aware counterpart to [`TryFrom<u32>`](HyperCallCode),
+    /// used once a guest has negotiated a revision via
+    /// [`HyperCallCode::HyperVisorNegotiateAbi`].
+    pub fn try_from_for_abi(
+        value: u32,
+        abi: HyperCallAbi,
+    ) -> Result<HyperCallCode, crate::InvalidHyperCallCode> {
+        let code = HyperCallCode::try_from(value)?;
+        if code.min_abi() <= abi {
+            Ok(code)
+        } else {
+            Err(crate::InvalidHyperCallCode(value))
+        }
+    }
+}
+
+/// Hypercall codes defined as of [`HyperCallAbi::V1`].
+///
+/// Re-exported here so callers can refer to the code set of a specific ABI
+/// revision, e.g. when validating a guest that only negotiated `V1`.
+/// [`v1::CODES`] is the source of truth [`HyperCallCode::min_abi`] and
+/// [`HyperCallCode::try_from_for_abi`] validate against.
+pub mod v1 {
+    pub use crate::HyperCallCode::{
+        HIVCPublishChannel, HIVCSubscribChannel, HIVCUnPublishChannel, HIVCUnSubscribChannel,
+        HyperVisorDebug, HyperVisorPrepareDisable, HypervisorDisable,
+    };
+
+    /// Every hypercall code defined as of this ABI revision.
+    pub const CODES: &[crate::HyperCallCode] = &[
+        HypervisorDisable,
+        HyperVisorPrepareDisable,
+        HyperVisorDebug,
+        HIVCPublishChannel,
+        HIVCSubscribChannel,
+        HIVCUnPublishChannel,
+        HIVCUnSubscribChannel,
+    ];
+}
+
+/// Hypercall codes introduced by [`HyperCallAbi::V2`].
+pub mod v2 {
+    pub use crate::HyperCallCode::{
+        HIVCNotifyChannel, HIVCWaitChannel, HyperVisorNegotiateAbi, HyperVisorQueryCapabilities,
+    };
+
+    /// Every hypercall code introduced by this ABI revision.
+    pub const CODES: &[crate::HyperCallCode] = &[
+        HyperVisorNegotiateAbi,
+        HyperVisorQueryCapabilities,
+        HIVCNotifyChannel,
+        HIVCWaitChannel,
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negotiate_picks_highest_common_revision() {
+        assert_eq!(HyperCallAbi::negotiate(0b10), Some(HyperCallAbi::V1));
+        assert_eq!(HyperCallAbi::negotiate(0b110), Some(HyperCallAbi::V2));
+        assert_eq!(HyperCallAbi::negotiate(0b100), Some(HyperCallAbi::V2));
+    }
+
+    #[test]
+    fn negotiate_returns_none_without_a_common_revision() {
+        assert_eq!(HyperCallAbi::negotiate(0), None);
+        assert_eq!(HyperCallAbi::negotiate(1), None);
+    }
+
+    #[test]
+    fn try_from_for_abi_rejects_codes_newer_than_the_negotiated_abi() {
+        let code = HyperCallCode::HIVCNotifyChannel as u32;
+        assert!(HyperCallCode::try_from_for_abi(code, HyperCallAbi::V1).is_err());
+        assert_eq!(
+            HyperCallCode::try_from_for_abi(code, HyperCallAbi::V2),
+            Ok(HyperCallCode::HIVCNotifyChannel)
+        );
+    }
+
+    #[test]
+    fn try_from_for_abi_allows_v1_codes_under_any_negotiated_abi() {
+        let code = HyperCallCode::HypervisorDisable as u32;
+        assert!(HyperCallCode::try_from_for_abi(code, HyperCallAbi::V1).is_ok());
+        assert!(HyperCallCode::try_from_for_abi(code, HyperCallAbi::V2).is_ok());
+    }
+
+    #[test]
+    fn every_code_min_abi_matches_its_code_set() {
+        for code in v1::CODES {
+            assert_eq!(code.min_abi(), HyperCallAbi::V1);
+        }
+        for code in v2::CODES {
+            assert_eq!(code.min_abi(), HyperCallAbi::V2);
+        }
+    }
+}
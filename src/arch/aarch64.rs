@@ -0,0 +1,72 @@
+// Copyright 2025 The Axvisor Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! aarch64 hypercall trapping convention.
+//!
+//! A guest traps into the hypervisor with an `hvc #imm` instruction: the
+//! hypercall code is the `imm` immediate (recovered from `ESR_EL2.ISS` by
+//! the trap handler), and arguments are passed in `x0`..`x5`.
+
+use super::HyperCallRegs;
+
+/// The aarch64 registers relevant to an `hvc` hypercall trap.
+#[repr(C)]
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct TrapFrame {
+    /// The `hvc` instruction's immediate, carrying the hypercall code.
+    pub hvc_imm: u32,
+
+    /// Argument registers `x0`..`x5`.
+    pub x: [usize; 6],
+}
+
+/// Extracts the hypercall code and arguments from an aarch64 trap frame.
+pub fn from_trap_frame(frame: &TrapFrame) -> HyperCallRegs {
+    HyperCallRegs {
+        code: frame.hvc_imm,
+        args: frame.x,
+    }
+}
+
+/// Builds an aarch64 trap frame for the given hypercall code and arguments.
+pub fn to_trap_frame(regs: &HyperCallRegs) -> TrapFrame {
+    TrapFrame {
+        hvc_imm: regs.code,
+        x: regs.args,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAME: TrapFrame = TrapFrame {
+        hvc_imm: 7,
+        x: [1, 2, 3, 4, 5, 6],
+    };
+
+    #[test]
+    fn trap_frame_roundtrips_through_hypercall_regs() {
+        assert_eq!(to_trap_frame(&from_trap_frame(&FRAME)), FRAME);
+    }
+
+    #[test]
+    fn hypercall_regs_roundtrips_through_trap_frame() {
+        let regs = HyperCallRegs {
+            code: 7,
+            args: [1, 2, 3, 4, 5, 6],
+        };
+        assert_eq!(from_trap_frame(&to_trap_frame(&regs)), regs);
+    }
+}
@@ -0,0 +1,50 @@
+// Copyright 2025 The Axvisor Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-architecture hypercall calling conventions.
+//!
+//! [`HyperCallCode`](crate::HyperCallCode) says nothing about how a guest
+//! actually traps into the hypervisor or which registers carry the code and
+//! its arguments; that mapping is architecture-specific and lives here, one
+//! submodule per target. Each submodule exposes the architecture's raw trap
+//! frame plus `from_trap_frame`/`to_trap_frame` conversions to and from the
+//! common [`HyperCallRegs`] so the hypervisor dispatcher and guest-side
+//! stubs share one source of truth for register placement.
+//!
+//! Each submodule is gated on `target_arch`, so a single-arch build only
+//! ever compiles (and tests) the module matching the host. CI must run
+//! `cargo check --target <aarch64|riscv64gc>-unknown-none` (or equivalent)
+//! in addition to the host-arch `cargo test` to exercise the other two
+//! submodules at all.
+
+#[cfg(target_arch = "aarch64")]
+pub mod aarch64;
+#[cfg(target_arch = "riscv64")]
+pub mod riscv64;
+#[cfg(target_arch = "x86_64")]
+pub mod x86_64;
+
+/// The hypercall code and arguments extracted from a guest trap, in the
+/// architecture-neutral form every dispatcher consumes.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct HyperCallRegs {
+    /// The raw hypercall code, as placed in the architecture's code register
+    /// (or immediate).
+    pub code: u32,
+
+    /// The six argument registers, in calling-convention order. This lines
+    /// up with the `regs` array consumed by
+    /// [`HyperCallArgs::decode`](crate::HyperCallArgs::decode).
+    pub args: [usize; 6],
+}
@@ -0,0 +1,72 @@
+// Copyright 2025 The Axvisor Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! riscv64 hypercall trapping convention.
+//!
+//! A guest traps into the hypervisor with `ecall`, following the SBI
+//! calling convention: the hypercall code is passed in `a7`, and arguments
+//! in `a0`..`a5`.
+
+use super::HyperCallRegs;
+
+/// The riscv64 registers relevant to an `ecall` hypercall trap.
+#[repr(C)]
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct TrapFrame {
+    /// Carries the hypercall code, per the SBI calling convention.
+    pub a7: usize,
+
+    /// Argument registers `a0`..`a5`.
+    pub a: [usize; 6],
+}
+
+/// Extracts the hypercall code and arguments from a riscv64 trap frame.
+pub fn from_trap_frame(frame: &TrapFrame) -> HyperCallRegs {
+    HyperCallRegs {
+        code: frame.a7 as u32,
+        args: frame.a,
+    }
+}
+
+/// Builds a riscv64 trap frame for the given hypercall code and arguments.
+pub fn to_trap_frame(regs: &HyperCallRegs) -> TrapFrame {
+    TrapFrame {
+        a7: regs.code as usize,
+        a: regs.args,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAME: TrapFrame = TrapFrame {
+        a7: 7,
+        a: [1, 2, 3, 4, 5, 6],
+    };
+
+    #[test]
+    fn trap_frame_roundtrips_through_hypercall_regs() {
+        assert_eq!(to_trap_frame(&from_trap_frame(&FRAME)), FRAME);
+    }
+
+    #[test]
+    fn hypercall_regs_roundtrips_through_trap_frame() {
+        let regs = HyperCallRegs {
+            code: 7,
+            args: [1, 2, 3, 4, 5, 6],
+        };
+        assert_eq!(from_trap_frame(&to_trap_frame(&regs)), regs);
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright 2025 The Axvisor Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! x86_64 hypercall trapping convention.
+//!
+//! A guest traps into the hypervisor with `vmmcall` (AMD-V) or `vmcall`
+//! (Intel VT-x): the hypercall code is passed in `rax`, and arguments in
+//! `rdi`, `rsi`, `rdx`, `rcx`, `r8`, `r9`.
+
+use super::HyperCallRegs;
+
+/// The x86_64 registers relevant to a `vmmcall`/`vmcall` hypercall trap.
+#[repr(C)]
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct TrapFrame {
+    /// Carries the hypercall code.
+    pub rax: usize,
+    /// Argument register 0.
+    pub rdi: usize,
+    /// Argument register 1.
+    pub rsi: usize,
+    /// Argument register 2.
+    pub rdx: usize,
+    /// Argument register 3.
+    pub rcx: usize,
+    /// Argument register 4.
+    pub r8: usize,
+    /// Argument register 5.
+    pub r9: usize,
+}
+
+/// Extracts the hypercall code and arguments from an x86_64 trap frame.
+pub fn from_trap_frame(frame: &TrapFrame) -> HyperCallRegs {
+    HyperCallRegs {
+        code: frame.rax as u32,
+        args: [
+            frame.rdi, frame.rsi, frame.rdx, frame.rcx, frame.r8, frame.r9,
+        ],
+    }
+}
+
+/// Builds an x86_64 trap frame for the given hypercall code and arguments.
+pub fn to_trap_frame(regs: &HyperCallRegs) -> TrapFrame {
+    TrapFrame {
+        rax: regs.code as usize,
+        rdi: regs.args[0],
+        rsi: regs.args[1],
+        rdx: regs.args[2],
+        rcx: regs.args[3],
+        r8: regs.args[4],
+        r9: regs.args[5],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAME: TrapFrame = TrapFrame {
+        rax: 7,
+        rdi: 1,
+        rsi: 2,
+        rdx: 3,
+        rcx: 4,
+        r8: 5,
+        r9: 6,
+    };
+
+    #[test]
+    fn trap_frame_roundtrips_through_hypercall_regs() {
+        assert_eq!(to_trap_frame(&from_trap_frame(&FRAME)), FRAME);
+    }
+
+    #[test]
+    fn hypercall_regs_roundtrips_through_trap_frame() {
+        let regs = HyperCallRegs {
+            code: 7,
+            args: [1, 2, 3, 4, 5, 6],
+        };
+        assert_eq!(from_trap_frame(&to_trap_frame(&regs)), regs);
+    }
+}
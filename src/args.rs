@@ -0,0 +1,299 @@
+// Copyright 2025 The Axvisor Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Typed hypercall arguments.
+//!
+//! Hypercall handlers historically received a raw `[usize; 6]` register
+//! blob and reimplemented the same marshalling described only in doc
+//! comments. [`HyperCallArgs`] gives every [`HyperCallCode`] a dedicated,
+//! validated parameter struct, with [`HyperCallArgs::decode`] and
+//! [`HyperCallArgs::encode`] as the single source of truth for the
+//! register layout on both ends of the call. `decode` takes the raw code
+//! register and validates it against [`HyperCallCode`] itself, so it is the
+//! one place a trap handler needs to call to turn an untyped guest trap
+//! into a typed, validated call.
+
+use crate::{HyperCallCode, InvalidHyperCallCode, IvcEventMask};
+
+/// A guest physical address, as carried in hypercall argument structs.
+///
+/// This is a thin, `repr(transparent)` wrapper around the raw address so
+/// that structs embedding it keep a stable, guest-visible layout.
+#[repr(transparent)]
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct GuestPhysAddr(pub usize);
+
+/// The typed arguments for a single hypercall invocation.
+///
+/// Each variant corresponds to one [`HyperCallCode`] and carries exactly
+/// the registers that code reads. Use [`HyperCallArgs::decode`] to validate
+/// a raw code and parse its register array, and [`HyperCallArgs::encode`]
+/// to go the other way when a guest stub or test needs to construct a call.
+#[repr(C)]
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum HyperCallArgs {
+    /// Arguments for [`HyperCallCode::HypervisorDisable`].
+    Disable,
+    /// Arguments for [`HyperCallCode::HyperVisorPrepareDisable`].
+    PrepareDisable,
+    /// Arguments for [`HyperCallCode::HyperVisorDebug`].
+    Debug,
+    /// Arguments for [`HyperCallCode::HyperVisorNegotiateAbi`].
+    NegotiateAbi {
+        /// Bitmask of ABI revisions the guest supports.
+        abi_mask: u64,
+    },
+    /// Arguments for [`HyperCallCode::HyperVisorQueryCapabilities`].
+    QueryCapabilities,
+    /// Arguments for [`HyperCallCode::HIVCPublishChannel`].
+    PublishChannel {
+        /// The unique key identifying the IVC channel.
+        key: u64,
+        /// Out-pointer for the shared memory region's base GPA.
+        shm_base_gpa_ptr: GuestPhysAddr,
+        /// Out-pointer for the shared memory region's size.
+        shm_size_ptr: GuestPhysAddr,
+    },
+    /// Arguments for [`HyperCallCode::HIVCSubscribChannel`].
+    SubscribeChannel {
+        /// The ID of the VM that published the channel.
+        publisher_vm_id: u64,
+        /// The key of the IVC channel to subscribe to.
+        key: u64,
+        /// Out-pointer for the shared memory region's base GPA.
+        shm_base_gpa_ptr: GuestPhysAddr,
+        /// Out-pointer for the shared memory region's size.
+        shm_size_ptr: GuestPhysAddr,
+    },
+    /// Arguments for [`HyperCallCode::HIVCUnPublishChannel`].
+    UnpublishChannel {
+        /// The key of the IVC channel to unpublish.
+        key: u64,
+    },
+    /// Arguments for [`HyperCallCode::HIVCUnSubscribChannel`].
+    UnsubscribeChannel {
+        /// The ID of the publisher VM.
+        publisher_vm_id: u64,
+        /// The key of the IVC channel to unsubscribe from.
+        key: u64,
+    },
+    /// Arguments for [`HyperCallCode::HIVCNotifyChannel`].
+    NotifyChannel {
+        /// The ID of the VM that published the channel.
+        publisher_vm_id: u64,
+        /// The key of the IVC channel to notify.
+        key: u64,
+        /// The event bits being signaled.
+        events: IvcEventMask,
+    },
+    /// Arguments for [`HyperCallCode::HIVCWaitChannel`].
+    WaitChannel {
+        /// The ID of the VM that published the channel.
+        publisher_vm_id: u64,
+        /// The key of the IVC channel to wait on.
+        key: u64,
+        /// The event bits this subscriber is interested in.
+        event_mask: IvcEventMask,
+        /// How long to wait, in hypervisor ticks: `0` polls without
+        /// blocking, `u64::MAX` waits indefinitely, any other value blocks
+        /// for up to that many ticks.
+        deadline: u64,
+    },
+}
+
+impl HyperCallArgs {
+    /// Validates a raw hypercall code and decodes the arguments for it out
+    /// of raw registers.
+    ///
+    /// `code` and `regs` are exactly what a trap handler would extract from
+    /// the guest's code and argument registers; this is the single point
+    /// where that raw register blob is validated against `HyperCallCode`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidHyperCallCode`] if `code` is not a valid hypercall
+    /// code.
+    pub fn decode(code: u32, regs: &[usize; 6]) -> Result<HyperCallArgs, InvalidHyperCallCode> {
+        let code = HyperCallCode::try_from(code)?;
+        Ok(match code {
+            HyperCallCode::HypervisorDisable => HyperCallArgs::Disable,
+            HyperCallCode::HyperVisorPrepareDisable => HyperCallArgs::PrepareDisable,
+            HyperCallCode::HyperVisorDebug => HyperCallArgs::Debug,
+            HyperCallCode::HyperVisorNegotiateAbi => HyperCallArgs::NegotiateAbi {
+                abi_mask: regs[0] as u64,
+            },
+            HyperCallCode::HyperVisorQueryCapabilities => HyperCallArgs::QueryCapabilities,
+            HyperCallCode::HIVCPublishChannel => HyperCallArgs::PublishChannel {
+                key: regs[0] as u64,
+                shm_base_gpa_ptr: GuestPhysAddr(regs[1]),
+                shm_size_ptr: GuestPhysAddr(regs[2]),
+            },
+            HyperCallCode::HIVCSubscribChannel => HyperCallArgs::SubscribeChannel {
+                publisher_vm_id: regs[0] as u64,
+                key: regs[1] as u64,
+                shm_base_gpa_ptr: GuestPhysAddr(regs[2]),
+                shm_size_ptr: GuestPhysAddr(regs[3]),
+            },
+            HyperCallCode::HIVCUnPublishChannel => HyperCallArgs::UnpublishChannel {
+                key: regs[0] as u64,
+            },
+            HyperCallCode::HIVCUnSubscribChannel => HyperCallArgs::UnsubscribeChannel {
+                publisher_vm_id: regs[0] as u64,
+                key: regs[1] as u64,
+            },
+            HyperCallCode::HIVCNotifyChannel => HyperCallArgs::NotifyChannel {
+                publisher_vm_id: regs[0] as u64,
+                key: regs[1] as u64,
+                events: IvcEventMask::from_bits(regs[2] as u32),
+            },
+            HyperCallCode::HIVCWaitChannel => HyperCallArgs::WaitChannel {
+                publisher_vm_id: regs[0] as u64,
+                key: regs[1] as u64,
+                event_mask: IvcEventMask::from_bits(regs[2] as u32),
+                deadline: regs[3] as u64,
+            },
+        })
+    }
+
+    /// Encodes these arguments back into a `(code, regs)` pair, the inverse
+    /// of [`HyperCallArgs::decode`].
+    pub fn encode(&self) -> (HyperCallCode, [usize; 6]) {
+        let mut regs = [0usize; 6];
+        let code = match self {
+            HyperCallArgs::Disable => HyperCallCode::HypervisorDisable,
+            HyperCallArgs::PrepareDisable => HyperCallCode::HyperVisorPrepareDisable,
+            HyperCallArgs::Debug => HyperCallCode::HyperVisorDebug,
+            HyperCallArgs::NegotiateAbi { abi_mask } => {
+                regs[0] = *abi_mask as usize;
+                HyperCallCode::HyperVisorNegotiateAbi
+            }
+            HyperCallArgs::QueryCapabilities => HyperCallCode::HyperVisorQueryCapabilities,
+            HyperCallArgs::PublishChannel {
+                key,
+                shm_base_gpa_ptr,
+                shm_size_ptr,
+            } => {
+                regs[0] = *key as usize;
+                regs[1] = shm_base_gpa_ptr.0;
+                regs[2] = shm_size_ptr.0;
+                HyperCallCode::HIVCPublishChannel
+            }
+            HyperCallArgs::SubscribeChannel {
+                publisher_vm_id,
+                key,
+                shm_base_gpa_ptr,
+                shm_size_ptr,
+            } => {
+                regs[0] = *publisher_vm_id as usize;
+                regs[1] = *key as usize;
+                regs[2] = shm_base_gpa_ptr.0;
+                regs[3] = shm_size_ptr.0;
+                HyperCallCode::HIVCSubscribChannel
+            }
+            HyperCallArgs::UnpublishChannel { key } => {
+                regs[0] = *key as usize;
+                HyperCallCode::HIVCUnPublishChannel
+            }
+            HyperCallArgs::UnsubscribeChannel {
+                publisher_vm_id,
+                key,
+            } => {
+                regs[0] = *publisher_vm_id as usize;
+                regs[1] = *key as usize;
+                HyperCallCode::HIVCUnSubscribChannel
+            }
+            HyperCallArgs::NotifyChannel {
+                publisher_vm_id,
+                key,
+                events,
+            } => {
+                regs[0] = *publisher_vm_id as usize;
+                regs[1] = *key as usize;
+                regs[2] = events.bits() as usize;
+                HyperCallCode::HIVCNotifyChannel
+            }
+            HyperCallArgs::WaitChannel {
+                publisher_vm_id,
+                key,
+                event_mask,
+                deadline,
+            } => {
+                regs[0] = *publisher_vm_id as usize;
+                regs[1] = *key as usize;
+                regs[2] = event_mask.bits() as usize;
+                regs[3] = *deadline as usize;
+                HyperCallCode::HIVCWaitChannel
+            }
+        };
+        (code, regs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_roundtrips(args: HyperCallArgs) {
+        let (code, regs) = args.encode();
+        assert_eq!(HyperCallArgs::decode(code as u32, &regs), Ok(args));
+    }
+
+    #[test]
+    fn roundtrips_simple_variants() {
+        assert_roundtrips(HyperCallArgs::Disable);
+        assert_roundtrips(HyperCallArgs::PrepareDisable);
+        assert_roundtrips(HyperCallArgs::Debug);
+        assert_roundtrips(HyperCallArgs::QueryCapabilities);
+        assert_roundtrips(HyperCallArgs::NegotiateAbi { abi_mask: 0b11 });
+    }
+
+    #[test]
+    fn roundtrips_ivc_channel_variants() {
+        assert_roundtrips(HyperCallArgs::PublishChannel {
+            key: 42,
+            shm_base_gpa_ptr: GuestPhysAddr(0x1000),
+            shm_size_ptr: GuestPhysAddr(0x2000),
+        });
+        assert_roundtrips(HyperCallArgs::SubscribeChannel {
+            publisher_vm_id: 1,
+            key: 42,
+            shm_base_gpa_ptr: GuestPhysAddr(0x1000),
+            shm_size_ptr: GuestPhysAddr(0x2000),
+        });
+        assert_roundtrips(HyperCallArgs::UnpublishChannel { key: 42 });
+        assert_roundtrips(HyperCallArgs::UnsubscribeChannel {
+            publisher_vm_id: 1,
+            key: 42,
+        });
+        assert_roundtrips(HyperCallArgs::NotifyChannel {
+            publisher_vm_id: 1,
+            key: 42,
+            events: IvcEventMask::bit(3),
+        });
+        assert_roundtrips(HyperCallArgs::WaitChannel {
+            publisher_vm_id: 1,
+            key: 42,
+            event_mask: IvcEventMask::bit(3),
+            deadline: 1_000,
+        });
+    }
+
+    #[test]
+    fn decode_rejects_invalid_code() {
+        assert_eq!(
+            HyperCallArgs::decode(u32::MAX, &[0; 6]),
+            Err(InvalidHyperCallCode(u32::MAX))
+        );
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright 2025 The Axvisor Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Hypercall capability enumeration.
+//!
+//! Not every AxVisor build implements every hypercall; [`HyperCallCode::HyperVisorQueryCapabilities`]
+//! lets a guest ask which codes this build supports instead of probing by
+//! trial-and-error and catching [`axerrno::AxError::Unsupported`].
+
+use crate::HyperCallCode;
+
+/// A bitmap of supported [`HyperCallCode`]s.
+///
+/// Bit `n` is set if and only if the hypercall code with numeric value `n`
+/// is implemented by this build. The bitmap is returned as the result of
+/// [`HyperCallCode::HyperVisorQueryCapabilities`].
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub struct CapabilitySet(u64);
+
+impl CapabilitySet {
+    /// A capability set with no hypercalls marked as supported.
+    pub const EMPTY: CapabilitySet = CapabilitySet(0);
+
+    /// Builds a capability set from its raw bitmap representation, e.g. the
+    /// `usize` returned by [`HyperCallCode::HyperVisorQueryCapabilities`].
+    pub const fn from_bits(bits: u64) -> CapabilitySet {
+        CapabilitySet(bits)
+    }
+
+    /// Returns the raw bitmap representation.
+    pub const fn bits(&self) -> u64 {
+        self.0
+    }
+
+    /// Folds an iterator of supported hypercall codes into a capability set.
+    pub fn from_supported<I>(codes: I) -> CapabilitySet
+    where
+        I: IntoIterator<Item = HyperCallCode>,
+    {
+        let mut set = CapabilitySet::EMPTY;
+        for code in codes {
+            set.insert(code);
+        }
+        set
+    }
+
+    /// Marks `code` as supported.
+    pub fn insert(&mut self, code: HyperCallCode) {
+        self.0 |= 1 << (code as u32);
+    }
+
+    /// Returns whether `code` is marked as supported in this set.
+    pub fn supports(&self, code: HyperCallCode) -> bool {
+        self.0 & (1 << (code as u32)) != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_set_supports_nothing() {
+        assert!(!CapabilitySet::EMPTY.supports(HyperCallCode::HypervisorDisable));
+        assert_eq!(CapabilitySet::EMPTY.bits(), 0);
+    }
+
+    #[test]
+    fn insert_marks_only_that_code_as_supported() {
+        let mut set = CapabilitySet::EMPTY;
+        set.insert(HyperCallCode::HIVCPublishChannel);
+        assert!(set.supports(HyperCallCode::HIVCPublishChannel));
+        assert!(!set.supports(HyperCallCode::HIVCSubscribChannel));
+    }
+
+    #[test]
+    fn from_supported_folds_every_code_in() {
+        let set = CapabilitySet::from_supported([
+            HyperCallCode::HypervisorDisable,
+            HyperCallCode::HIVCWaitChannel,
+        ]);
+        assert!(set.supports(HyperCallCode::HypervisorDisable));
+        assert!(set.supports(HyperCallCode::HIVCWaitChannel));
+        assert!(!set.supports(HyperCallCode::HIVCNotifyChannel));
+    }
+
+    #[test]
+    fn from_bits_roundtrips_through_bits() {
+        let mut set = CapabilitySet::EMPTY;
+        set.insert(HyperCallCode::HyperVisorQueryCapabilities);
+        assert_eq!(CapabilitySet::from_bits(set.bits()), set);
+    }
+}
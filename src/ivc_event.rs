@@ -0,0 +1,100 @@
+// Copyright 2025 The Axvisor Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! IVC event-channel notification.
+//!
+//! Shared-memory IVC channels alone give a subscriber no way to learn that
+//! new data is available short of busy-polling the region.
+//! [`crate::HyperCallCode::HIVCNotifyChannel`] lets a publisher signal a
+//! channel, and [`crate::HyperCallCode::HIVCWaitChannel`] lets a subscriber
+//! block until a signal arrives (or a deadline elapses). [`IvcEventMask`]
+//! lets a subscriber narrow which event bits on a channel it cares about.
+
+/// A bitmask of event bits a subscriber can register interest in, or a
+/// publisher can signal, on a single IVC channel.
+///
+/// The meaning of individual bits is defined by the protocol running over
+/// the channel; this type only provides the bitmap mechanics.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub struct IvcEventMask(u32);
+
+impl IvcEventMask {
+    /// No event bits set.
+    pub const NONE: IvcEventMask = IvcEventMask(0);
+
+    /// Every event bit set.
+    pub const ALL: IvcEventMask = IvcEventMask(u32::MAX);
+
+    /// Builds a mask from its raw bitmap representation.
+    pub const fn from_bits(bits: u32) -> IvcEventMask {
+        IvcEventMask(bits)
+    }
+
+    /// A mask with only the given bit index set, or [`IvcEventMask::NONE`]
+    /// if `index` is out of range for a 32-bit mask.
+    pub const fn bit(index: u32) -> IvcEventMask {
+        match 1u32.checked_shl(index) {
+            Some(bit) => IvcEventMask(bit),
+            None => IvcEventMask::NONE,
+        }
+    }
+
+    /// Returns the raw bitmap representation.
+    pub const fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns whether every bit in `other` is also set in `self`.
+    pub const fn contains(&self, other: IvcEventMask) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns whether any bit is set in both `self` and `other`.
+    pub const fn intersects(&self, other: IvcEventMask) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+
+impl core::ops::BitOr for IvcEventMask {
+    type Output = IvcEventMask;
+
+    fn bitor(self, rhs: IvcEventMask) -> IvcEventMask {
+        IvcEventMask(self.0 | rhs.0)
+    }
+}
+
+impl core::ops::BitAnd for IvcEventMask {
+    type Output = IvcEventMask;
+
+    fn bitand(self, rhs: IvcEventMask) -> IvcEventMask {
+        IvcEventMask(self.0 & rhs.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bit_sets_only_the_requested_index() {
+        assert_eq!(IvcEventMask::bit(0).bits(), 0b1);
+        assert_eq!(IvcEventMask::bit(31).bits(), 1 << 31);
+    }
+
+    #[test]
+    fn bit_out_of_range_returns_none_instead_of_panicking() {
+        assert_eq!(IvcEventMask::bit(32), IvcEventMask::NONE);
+        assert_eq!(IvcEventMask::bit(u32::MAX), IvcEventMask::NONE);
+    }
+}
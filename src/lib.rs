@@ -25,6 +25,9 @@
 //!
 //! - [`HyperCallCode`]: An enumeration of all supported hypercall operations
 //! - [`HyperCallResult`]: The result type returned by hypercall handlers
+//! - [`HyperCallArgs`]: Strongly-typed, per-code arguments decoded from raw registers
+//! - [`HyperCallClass`]: Privilege classification enforcing control-VM-only operations
+//! - [`arch`]: Per-architecture mapping from trap registers to hypercall code/arguments
 //!
 //! # Supported Hypercalls
 //!
@@ -32,6 +35,10 @@
 //!
 //! - **Hypervisor Control**: Enable/disable hypervisor functionality
 //! - **Inter-VM Communication (IVC)**: Shared memory channels between VMs
+//! - **ABI Negotiation**: Guest/hypervisor hypercall ABI revision handshake,
+//!   see the [`abi`] module
+//! - **Capability Query**: Feature detection for optional hypercalls, see
+//!   the [`capability`] module
 //!
 //! # Example
 //!
@@ -58,6 +65,20 @@
 
 use axerrno::AxResult;
 
+pub mod abi;
+pub mod arch;
+pub mod args;
+pub mod capability;
+pub mod ivc_event;
+pub mod privilege;
+
+pub use abi::HyperCallAbi;
+pub use arch::HyperCallRegs;
+pub use args::{GuestPhysAddr, HyperCallArgs};
+pub use capability::CapabilitySet;
+pub use ivc_event::IvcEventMask;
+pub use privilege::HyperCallClass;
+
 /// Hypercall operation codes for AxVisor.
 ///
 /// Each variant represents a specific operation that a guest VM can request
@@ -68,6 +89,9 @@ use axerrno::AxResult;
 ///
 /// - **Hypervisor Control** (0-2): Operations to control the hypervisor lifecycle
 /// - **IVC Operations** (3-6): Inter-VM communication channel management
+/// - **ABI Negotiation** (7): Guest/hypervisor ABI revision handshake, see [`abi`]
+/// - **Capability Query** (8): Feature detection, see [`capability`]
+/// - **IVC Events** (9-10): Channel notify/wait doorbell, see [`ivc_event`]
 ///
 /// # Example
 ///
@@ -183,6 +207,77 @@ pub enum HyperCallCode {
     /// - `Ok(0)` on success
     /// - `Err(_)` if unsubscription fails
     HIVCUnSubscribChannel = 6,
+
+    /// Negotiate the hypercall ABI revision.
+    ///
+    /// The guest passes a bitmask of the ABI revisions it is willing to
+    /// speak (bit `n` set means it understands [`abi::HyperCallAbi`] variant
+    /// `n`); the hypervisor responds with the highest revision both sides
+    /// support. A guest should issue this before relying on any hypercall
+    /// code with [`HyperCallCode::min_abi`] above [`abi::HyperCallAbi::V1`].
+    ///
+    /// # Arguments
+    ///
+    /// - `abi_mask`: Bitmask of ABI revisions the guest supports
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(version)` where `version` is the negotiated [`abi::HyperCallAbi`]
+    ///   encoded as its numeric value
+    /// - `Err(_)` if no common revision exists
+    HyperVisorNegotiateAbi = 7,
+
+    /// Query which hypercalls this hypervisor build supports.
+    ///
+    /// Lets a guest feature-detect before invoking a hypercall instead of
+    /// probing by trial-and-error and catching `Err(AxError::Unsupported)`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(bits)` where `bits` is a [`capability::CapabilitySet`] bitmap
+    ///   cast to `usize`
+    HyperVisorQueryCapabilities = 8,
+
+    /// Notify subscribers of an IVC channel that new data is available.
+    ///
+    /// Injects a virtual interrupt into (or wakes) any subscriber currently
+    /// blocked in [`HyperCallCode::HIVCWaitChannel`] on this channel, for
+    /// each event bit in `events` the subscriber registered interest in.
+    ///
+    /// # Arguments
+    ///
+    /// - `publisher_vm_id`: The ID of the VM that published the channel
+    /// - `key`: The key of the IVC channel to notify
+    /// - `events`: The [`ivc_event::IvcEventMask`] bits being signaled
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(0)` on success
+    /// - `Err(_)` if the channel does not exist
+    HIVCNotifyChannel = 9,
+
+    /// Block until an IVC channel is notified, or a deadline elapses.
+    ///
+    /// # Arguments
+    ///
+    /// - `publisher_vm_id`: The ID of the VM that published the channel
+    /// - `key`: The key of the IVC channel to wait on
+    /// - `event_mask`: The [`ivc_event::IvcEventMask`] bits this subscriber
+    ///   is interested in
+    /// - `deadline`: How long to wait, in hypervisor ticks:
+    ///   - `0` performs a non-blocking poll: return immediately instead of
+    ///     blocking
+    ///   - `u64::MAX` waits indefinitely, with no timeout
+    ///   - any other value blocks for up to that many ticks
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(events)` with the `IvcEventMask` bits that were signaled
+    /// - `Err(AxError::WouldBlock)` if `deadline` is `0` and nothing had
+    ///   already been signaled
+    /// - `Err(AxError::TimedOut)` if a nonzero, non-`u64::MAX` `deadline`
+    ///   elapsed before anything was signaled
+    HIVCWaitChannel = 10,
 }
 
 /// Error type for invalid hypercall code conversion.
@@ -210,6 +305,10 @@ impl TryFrom<u32> for HyperCallCode {
             4 => Ok(HyperCallCode::HIVCSubscribChannel),
             5 => Ok(HyperCallCode::HIVCUnPublishChannel),
             6 => Ok(HyperCallCode::HIVCUnSubscribChannel),
+            7 => Ok(HyperCallCode::HyperVisorNegotiateAbi),
+            8 => Ok(HyperCallCode::HyperVisorQueryCapabilities),
+            9 => Ok(HyperCallCode::HIVCNotifyChannel),
+            10 => Ok(HyperCallCode::HIVCWaitChannel),
             _ => Err(InvalidHyperCallCode(value)),
         }
     }
@@ -236,6 +335,16 @@ impl core::fmt::Debug for HyperCallCode {
             HyperCallCode::HIVCUnSubscribChannel => {
                 write!(f, "HIVCUnSubscribChannel {:#x}", *self as u32)
             }
+            HyperCallCode::HyperVisorNegotiateAbi => {
+                write!(f, "HyperVisorNegotiateAbi {:#x}", *self as u32)
+            }
+            HyperCallCode::HyperVisorQueryCapabilities => {
+                write!(f, "HyperVisorQueryCapabilities {:#x}", *self as u32)
+            }
+            HyperCallCode::HIVCNotifyChannel => {
+                write!(f, "HIVCNotifyChannel {:#x}", *self as u32)
+            }
+            HyperCallCode::HIVCWaitChannel => write!(f, "HIVCWaitChannel {:#x}", *self as u32),
         }?;
         write!(f, ")")
     }
@@ -0,0 +1,114 @@
+// Copyright 2025 The Axvisor Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Hypercall privilege classification.
+//!
+//! Lifecycle operations such as [`HyperCallCode::HypervisorDisable`] must
+//! only be issuable by a control/management VM, not by arbitrary guests.
+//! [`HyperCallClass`] separates those operations from the ones any VM may
+//! perform on itself, so the dispatcher can enforce the split in one place
+//! instead of every call site checking the caller's privilege by hand.
+
+use axerrno::{AxError, AxResult};
+
+use crate::HyperCallCode;
+
+/// The privilege class of a [`HyperCallCode`].
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum HyperCallClass {
+    /// A VM may invoke this hypercall on itself.
+    SelfService,
+
+    /// Only a control/management VM may invoke this hypercall.
+    Control,
+}
+
+impl HyperCallCode {
+    /// The privilege class this code belongs to.
+    pub fn class(&self) -> HyperCallClass {
+        match self {
+            HyperCallCode::HypervisorDisable
+            | HyperCallCode::HyperVisorPrepareDisable
+            | HyperCallCode::HyperVisorDebug => HyperCallClass::Control,
+
+            HyperCallCode::HyperVisorNegotiateAbi
+            | HyperCallCode::HyperVisorQueryCapabilities
+            | HyperCallCode::HIVCPublishChannel
+            | HyperCallCode::HIVCSubscribChannel
+            | HyperCallCode::HIVCUnPublishChannel
+            | HyperCallCode::HIVCUnSubscribChannel
+            | HyperCallCode::HIVCNotifyChannel
+            | HyperCallCode::HIVCWaitChannel => HyperCallClass::SelfService,
+        }
+    }
+
+    /// Returns whether a caller may invoke this code, given whether it is
+    /// the control/management VM.
+    pub fn allowed_for(&self, caller_is_control: bool) -> bool {
+        match self.class() {
+            HyperCallClass::SelfService => true,
+            HyperCallClass::Control => caller_is_control,
+        }
+    }
+
+    /// Checks whether a caller may invoke this code, given whether it is
+    /// the control/management VM.
+    ///
+    /// The dispatcher should call this before handling any hypercall:
+    ///
+    /// ```ignore
+    /// code.authorize(caller_is_control)?;
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the caller may invoke this code
+    /// - `Err(AxError::PermissionDenied)` if a [`HyperCallClass::Control`]
+    ///   code is invoked by a VM that lacks the control capability
+    pub fn authorize(&self, caller_is_control: bool) -> AxResult<()> {
+        if self.allowed_for(caller_is_control) {
+            Ok(())
+        } else {
+            Err(AxError::PermissionDenied)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn control_class_code_is_rejected_for_non_control_callers() {
+        let code = HyperCallCode::HypervisorDisable;
+        assert_eq!(code.class(), HyperCallClass::Control);
+        assert!(!code.allowed_for(false));
+        assert!(code.allowed_for(true));
+    }
+
+    #[test]
+    fn self_service_class_code_is_always_allowed() {
+        let code = HyperCallCode::HIVCPublishChannel;
+        assert_eq!(code.class(), HyperCallClass::SelfService);
+        assert!(code.allowed_for(false));
+        assert!(code.allowed_for(true));
+    }
+
+    #[test]
+    fn authorize_denies_control_code_for_non_control_caller() {
+        let code = HyperCallCode::HyperVisorPrepareDisable;
+        assert_eq!(code.authorize(false), Err(AxError::PermissionDenied));
+        assert_eq!(code.authorize(true), Ok(()));
+    }
+}